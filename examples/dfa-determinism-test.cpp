@@ -0,0 +1,100 @@
+#include <cstdint>
+#include <iostream>
+#include <memory>
+#include <set>
+#include <stack>
+#include <string>
+#include <vector>
+
+#include <log_surgeon/Lexer.hpp>
+#include <log_surgeon/Schema.hpp>
+
+using log_surgeon::finite_automata::RegexDFA;
+using log_surgeon::finite_automata::RegexDFAByteState;
+using log_surgeon::finite_automata::RegexNFA;
+using log_surgeon::finite_automata::RegexNFAByteState;
+using log_surgeon::lexers::ByteLexer;
+using log_surgeon::ParserAST;
+using log_surgeon::SchemaVarAST;
+using std::string;
+using std::unique_ptr;
+
+/**
+ * Builds a DFA (in its own NFA) from rules that overlap, so that many of its
+ * accepting states have multiple tags
+ * @return The DFA
+ */
+auto build_dfa() -> unique_ptr<RegexDFA<RegexDFAByteState>> {
+    log_surgeon::Schema schema;
+    schema.add_variable("int", "\\-{0,1}[0-9]+", -1);
+    schema.add_variable("float", "\\-{0,1}[0-9]+\\.[0-9]+", -1);
+    schema.add_variable("hex", "[a-fA-F0-9]+", -1);
+    schema.add_variable("hasNumber", ".*\\d.*", -1);
+    schema.add_variable("equals", ".*=.*[a-zA-Z0-9].*", -1);
+    schema.add_variable("word", "[a-zA-Z]+", -1);
+    RegexNFA<RegexNFAByteState> nfa;
+    auto schema_ast = schema.release_schema_ast_ptr();
+    uint32_t rule_id = 0;
+    for (unique_ptr<ParserAST> const& parser_ast : schema_ast->m_schema_vars) {
+        auto* var_ast = dynamic_cast<SchemaVarAST*>(parser_ast.get());
+        ByteLexer::Rule rule(rule_id++, std::move(var_ast->m_regex_ptr));
+        rule.add_ast(&nfa);
+    }
+    return ByteLexer::nfa_to_dfa(nfa);
+}
+
+/**
+ * @param dfa
+ * @return Whether every reachable state's tags are in the order their rules
+ * were added (i.e., strictly increasing rule IDs)
+ */
+auto tags_follow_rule_order(RegexDFA<RegexDFAByteState> const& dfa) -> bool {
+    std::set<RegexDFAByteState const*> visited;
+    std::stack<RegexDFAByteState const*> unvisited;
+    unvisited.push(dfa.get_root());
+    while (false == unvisited.empty()) {
+        RegexDFAByteState const* state = unvisited.top();
+        unvisited.pop();
+        if (false == visited.insert(state).second) {
+            continue;
+        }
+        std::vector<int> const& tags = state->get_tags();
+        for (size_t i = 1; i < tags.size(); i++) {
+            if (tags[i - 1] >= tags[i]) {
+                return false;
+            }
+        }
+        for (uint32_t i = 0; i < log_surgeon::cSizeOfByte; i++) {
+            if (RegexDFAByteState const* next_state = state->next(i); nullptr != next_state) {
+                unvisited.push(next_state);
+            }
+        }
+    }
+    return true;
+}
+
+auto main() -> int {
+    auto dfa1 = build_dfa();
+    // Keep some allocations alive between the builds so the second DFA's NFA
+    // states end up at different (relative) addresses than the first's
+    std::vector<unique_ptr<string>> padding;
+    for (int i = 0; i < 1000; i++) {
+        padding.push_back(std::make_unique<string>(i % 64 + 1, 'x'));
+    }
+    auto dfa2 = build_dfa();
+
+    bool passed = true;
+    if (dfa1->serialize_to_dot() != dfa2->serialize_to_dot()) {
+        std::cout << "DFAs built from the same rules serialize differently" << std::endl;
+        passed = false;
+    }
+    if (false == tags_follow_rule_order(*dfa1) || false == tags_follow_rule_order(*dfa2)) {
+        std::cout << "DFA state tags don't follow the rules' order" << std::endl;
+        passed = false;
+    }
+    if (false == passed) {
+        return 1;
+    }
+    std::cout << "DFA construction is deterministic" << std::endl;
+    return 0;
+}
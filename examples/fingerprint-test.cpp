@@ -0,0 +1,93 @@
+#include <cstdint>
+#include <iostream>
+#include <string>
+#include <vector>
+
+#include <log_surgeon/BufferParser.hpp>
+#include <log_surgeon/Constants.hpp>
+#include <log_surgeon/LogEvent.hpp>
+#include <log_surgeon/SchemaParser.hpp>
+
+using log_surgeon::BufferParser;
+using log_surgeon::ErrorCode;
+using log_surgeon::LogEventView;
+using log_surgeon::SchemaParser;
+using std::string;
+using std::vector;
+
+namespace {
+/**
+ * An event's expected logtype and fingerprints. The fingerprints are part of
+ * the API, so these values must never change.
+ */
+struct TestVector {
+    string m_logtype;
+    uint64_t m_fingerprint;
+    uint64_t m_int_fingerprint;
+    uint64_t m_int_and_missing_fingerprint;
+};
+
+vector<TestVector> const cTestVectors{
+        {" took <int> ms on node <int>\n",
+         0x1bac'06a4'bd2c'9016,
+         0x075b'4ed5'0836'7333,
+         0xb646'c49f'd747'7694},
+        {" took <int> ms on node <int>\n",
+         0x1bac'06a4'bd2c'9016,
+         0x075b'53d5'0836'7bb2,
+         0xcbf7'a116'a988'3477},
+        {" done<newLine>", 0xf1c4'0407'7fd4'a0f5, 0xeac8'9326'ec8c'7c97, 0x6ed0'1e14'3e95'dbd0}
+};
+}  // namespace
+
+auto main() -> int {
+    BufferParser parser{SchemaParser::try_schema_string(
+            "timestamp:\\d{4}\\-\\d{2}\\-\\d{2} \\d{2}:\\d{2}:\\d{2}\n"
+            "delimiters: \\t\\r\\n:,=\n"
+            "int:\\-{0,1}\\d+"
+    )};
+    string log{
+            "2023-01-01 12:00:00 took 25 ms on node 3\n"
+            "2023-01-02 08:30:00 took 25 ms on node 4\n"
+            "2023-01-02 08:30:01 done\n"
+    };
+    parser.reset();
+    size_t offset{0};
+    size_t num_events{0};
+    bool passed = true;
+    while (false == parser.done()) {
+        if (ErrorCode::Success != parser.parse_next_event(log.data(), log.size(), offset, true))
+        {
+            std::cout << "Failed to parse the log" << std::endl;
+            return 1;
+        }
+        LogEventView const& event = parser.get_log_parser().get_log_event_view();
+        if (num_events >= cTestVectors.size()) {
+            break;
+        }
+        TestVector const& expected = cTestVectors[num_events];
+        if (event.get_logtype() != expected.m_logtype) {
+            std::cout << "Event " << num_events << " has the logtype '" << event.get_logtype()
+                      << "'" << std::endl;
+            passed = false;
+        }
+        if (event.get_fingerprint({}) != expected.m_fingerprint
+            || event.get_fingerprint({"int"}) != expected.m_int_fingerprint
+            || event.get_fingerprint({"int", "missing"}) != expected.m_int_and_missing_fingerprint)
+        {
+            std::cout << "Event " << num_events << " has the wrong fingerprint" << std::endl;
+            passed = false;
+        }
+        num_events++;
+    }
+    if (cTestVectors.size() != num_events) {
+        std::cout << "Parsed " << num_events << " events instead of " << cTestVectors.size()
+                  << std::endl;
+        passed = false;
+    }
+    if (false == passed) {
+        return 1;
+    }
+    std::cout << "Fingerprints match the test vectors" << std::endl;
+    return 0;
+}
@@ -0,0 +1,295 @@
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <iostream>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include <log_surgeon/BufferParser.hpp>
+#include <log_surgeon/Constants.hpp>
+#include <log_surgeon/LogEvent.hpp>
+#include <log_surgeon/Schema.hpp>
+#include <log_surgeon/SchemaParser.hpp>
+
+using log_surgeon::BufferParser;
+using log_surgeon::ErrorCode;
+using log_surgeon::RegexLimits;
+using log_surgeon::Schema;
+using log_surgeon::SchemaAST;
+using log_surgeon::SchemaParser;
+using log_surgeon::SchemaVarAST;
+using std::string;
+using std::unique_ptr;
+
+namespace {
+int num_failures = 0;
+
+/**
+ * Records a failure if parsing the schema throws
+ * @param description
+ * @param schema
+ * @param limits
+ */
+auto expect_parses(string const& description, string const& schema, RegexLimits const& limits = {})
+        -> void {
+    try {
+        SchemaParser::try_schema_string(schema, limits);
+    } catch (std::exception const& e) {
+        std::cout << "FAILED: " << description << ": " << e.what() << std::endl;
+        num_failures++;
+    }
+}
+
+/**
+ * Records a failure unless parsing the schema throws an error containing
+ * `expected_error`
+ * @param description
+ * @param schema
+ * @param expected_error
+ */
+auto expect_error(string const& description, string const& schema, string const& expected_error)
+        -> void {
+    try {
+        SchemaParser::try_schema_string(schema);
+    } catch (std::exception const& e) {
+        if (string(e.what()).find(expected_error) == string::npos) {
+            std::cout << "FAILED: " << description << ": unexpected error: " << e.what()
+                      << std::endl;
+            num_failures++;
+        }
+        return;
+    }
+    std::cout << "FAILED: " << description << ": parsed without an error" << std::endl;
+    num_failures++;
+}
+
+/**
+ * Records a failure unless whether `input` matches the pattern is `expected`
+ * @param pattern
+ * @param input
+ * @param expected
+ * @param case_insensitive
+ */
+auto expect_match(
+        string const& pattern,
+        string const& input,
+        bool expected,
+        bool case_insensitive = false
+) -> void {
+    try {
+        Schema schema;
+        schema.add_variable("v", pattern, -1, case_insensitive);
+        if (expected != schema.match_variable("v", input)) {
+            std::cout << "FAILED: '" << pattern << "' should " << (expected ? "" : "not ")
+                      << "match '" << input << "'" << std::endl;
+            num_failures++;
+        }
+    } catch (std::exception const& e) {
+        std::cout << "FAILED: '" << pattern << "': " << e.what() << std::endl;
+        num_failures++;
+    }
+}
+
+/**
+ * @param pattern
+ * @return The pattern parsed as a schema variable's regex and then serialized
+ */
+auto parse_and_serialize(string const& pattern) -> string {
+    unique_ptr<SchemaAST> schema_ast = SchemaParser::try_schema_string("v:" + pattern);
+    auto const* schema_var_ast = dynamic_cast<SchemaVarAST*>(schema_ast->m_schema_vars[0].get());
+    return schema_var_ast->m_regex_ptr->serialize();
+}
+
+auto test_long_patterns() -> void {
+    expect_parses(
+            "consecutive long variables",
+            "v1:" + string(995, 'a') + "\nv2:" + string(1000, 'a')
+    );
+    RegexLimits limits;
+    limits.m_max_pattern_length = 5000;
+    limits.m_max_expanded_size = 5000;
+    limits.m_max_ast_depth = 5000;
+    expect_parses("a variable longer than the default limit", "v:" + string(5000, 'a'), limits);
+    expect_error(
+            "a variable longer than the limit",
+            "v:" + string(1001, 'a'),
+            "has a pattern of 1001 characters"
+    );
+}
+
+auto test_bracket_expressions() -> void {
+    expect_match(R"([\d-]+)", "12-3", true);
+    expect_match(R"([\d-]+)", "12a", false);
+    expect_match(R"([-a]+)", "a-a", true);
+    expect_match(R"([^-a]+)", "bc", true);
+    expect_match(R"([^-a]+)", "b-c", false);
+    expect_match(R"([\w.]+)", "a_1.b", true);
+    expect_match(R"([\w.]+)", "a,b", false);
+    expect_match(R"([\w.-]+)", "a-1.b", true);
+    expect_match(R"([.-]+)", ".-", true);
+    expect_match(R"([.-]+)", "a", false);
+    expect_match(R"([a\s]+)", "a a\t", true);
+    expect_match(R"([a\s]+)", "ab", false);
+}
+
+auto test_posix_classes() -> void {
+    expect_match("[[:alpha:]_]+", "ab_C", true);
+    expect_match("[[:alpha:]_]+", "ab1", false);
+    expect_match("[^[:digit:][:space:]]+", "ab!", true);
+    expect_match("[^[:digit:][:space:]]+", "a b", false);
+    expect_match("[[:xdigit:]]+", "09afAF", true);
+    expect_match("[[:xdigit:]]+", "0g", false);
+    expect_error("an unknown POSIX class", "v:[[:foo:]]", "PosixClass: '[:foo:]'");
+}
+
+auto test_repetitions() -> void {
+    expect_match("a{2,}", "a", false);
+    expect_match("a{2,}", "aa", true);
+    expect_match("a{2,}", "aaaaa", true);
+    expect_match("a{0,}b", "b", true);
+    expect_match("a{2,3}", "aaaa", false);
+    expect_error("a zero repetition count", "v:a{0}", "the count must be at least 1");
+    expect_error(
+            "a repetition range whose maximum is less than its minimum",
+            "v:a{5,3}",
+            "the maximum must be at least 1 and no less than the minimum"
+    );
+    expect_error(
+            "a repetition count above the limit",
+            "v:a{1001}",
+            "repetition count 1001 is greater than the maximum of 1000"
+    );
+}
+
+auto test_escapes() -> void {
+    expect_match(R"(\x41\x62)", "Ab", true);
+    expect_match(R"([\x41-\x43]+)", "ABC", true);
+    expect_match(R"([\x41-\x43]+)", "ABD", false);
+    expect_match(R"(\101\60\7)", "A0\a", true);
+    expect_match(R"(\377)", "\xff", true);
+    expect_error("a hex escape with a non-hex digit", R"(v:\xG1)", R"(HexEscape: '\xG1')");
+    expect_error("an octal escape above one byte", R"(v:\400)", R"(OctalEscape: '\400')");
+}
+
+auto test_case_insensitivity() -> void {
+    expect_match("error", "ErRoR", true, true);
+    expect_match("error", "ErRoR", false, false);
+    expect_match("[a-c]+", "aBC", true, true);
+    expect_match("[^a-c]+", "B", false, true);
+    expect_match("[^a-c]+", "d", true, true);
+}
+
+auto test_line_start_anchor() -> void {
+    unique_ptr<SchemaAST> schema_ast
+            = SchemaParser::try_schema_string("delimiters: \\n\nlevel:^[A-Z]+\nword:[a-z]+");
+    auto const* level_ast = dynamic_cast<SchemaVarAST*>(schema_ast->m_schema_vars[0].get());
+    auto const* word_ast = dynamic_cast<SchemaVarAST*>(schema_ast->m_schema_vars[1].get());
+    if (false == level_ast->m_is_anchored_to_line_start
+        || word_ast->m_is_anchored_to_line_start)
+    {
+        std::cout << "FAILED: only '^' patterns should be anchored to the line start"
+                  << std::endl;
+        num_failures++;
+    }
+
+    // Only the uppercase words at the start of a line should be levels
+    BufferParser parser{std::move(schema_ast)};
+    std::optional<uint32_t> level_id = parser.get_variable_id("level");
+    string log{"INFO a INFO\nWARN b\n"};
+    parser.reset();
+    size_t offset{0};
+    size_t num_levels{0};
+    while (false == parser.done()) {
+        if (ErrorCode::Success != parser.parse_next_event(log.data(), log.size(), offset, true))
+        {
+            break;
+        }
+        num_levels += parser.get_log_parser().get_log_event_view().get_variables(*level_id).size();
+    }
+    if (2 != num_levels) {
+        std::cout << "FAILED: '^[A-Z]+' matched " << num_levels
+                  << " times instead of at the start of the 2 lines" << std::endl;
+        num_failures++;
+    }
+}
+
+auto test_serialize_round_trip() -> void {
+    std::vector<string> const patterns{
+            R"(abc)",
+            R"(\-{0,1}[0-9]+\.[0-9]+)",
+            R"((ab)|(cd)e)",
+            R"([^a-c\d]*x+)",
+            R"(a{2,}b{3}c{1,4})",
+            R"([[:alpha:]_]+)",
+            R"(\x01\t\.\\)",
+            R"([\w.-]+)",
+            R"(.*=.*)"
+    };
+    for (auto const& pattern : patterns) {
+        try {
+            string const serialized = parse_and_serialize(pattern);
+            if (string const reserialized = parse_and_serialize(serialized);
+                serialized != reserialized)
+            {
+                std::cout << "FAILED: '" << pattern << "' serialized to '" << serialized
+                          << "', which serialized to '" << reserialized << "'" << std::endl;
+                num_failures++;
+            }
+        } catch (std::exception const& e) {
+            std::cout << "FAILED: serializing '" << pattern << "': " << e.what() << std::endl;
+            num_failures++;
+        }
+    }
+    if (string const serialized = parse_and_serialize(R"(\d+)"); "[0-9]+" != serialized) {
+        std::cout << "FAILED: '\\d+' serialized to '" << serialized << "'" << std::endl;
+        num_failures++;
+    }
+}
+
+auto test_escape_regex() -> void {
+    string text{"a.b*c+d?(e)[f]{g}|h\\i-j^k$l\tm\x01n"};
+    expect_match(SchemaParser::escape_regex(text), text, true);
+    expect_match(SchemaParser::escape_regex(text), "axb*c+d?(e)[f]{g}|h\\i-j^k$l\tm\x01n", false);
+    expect_match(SchemaParser::escape_regex("a.b"), "a.b", true);
+    expect_match(SchemaParser::escape_regex("a.b"), "acb", false);
+}
+
+auto test_match_variable() -> void {
+    Schema schema;
+    schema.add_variable("int", R"(\-{0,1}[0-9]+)", -1);
+    schema.add_variable("num", R"([0-9]+)", -1);
+    schema.add_variable("num", R"([0-9]+\.[0-9]+)", -1);
+    if (false == schema.match_variable("int", "-12") || schema.match_variable("int", "1.5")
+        || false == schema.match_variable("num", "1.5") || schema.match_variable("num", "-1"))
+    {
+        std::cout << "FAILED: match_variable gave the wrong result" << std::endl;
+        num_failures++;
+    }
+    try {
+        schema.match_variable("missing", "1");
+        std::cout << "FAILED: match_variable found a variable that doesn't exist" << std::endl;
+        num_failures++;
+    } catch (std::runtime_error const&) {
+    }
+}
+}  // namespace
+
+auto main() -> int {
+    test_long_patterns();
+    test_bracket_expressions();
+    test_posix_classes();
+    test_repetitions();
+    test_escapes();
+    test_case_insensitivity();
+    test_line_start_anchor();
+    test_serialize_round_trip();
+    test_escape_regex();
+    test_match_variable();
+    if (0 != num_failures) {
+        return 1;
+    }
+    std::cout << "All schema parser checks passed" << std::endl;
+    return 0;
+}
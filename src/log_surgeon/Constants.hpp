@@ -6,6 +6,8 @@
 
 namespace log_surgeon {
 constexpr uint32_t cUnicodeMax = 0x10'FFFF;
+constexpr uint32_t cUnicodeSurrogateMin = 0xD800;
+constexpr uint32_t cUnicodeSurrogateMax = 0xDFFF;
 constexpr uint32_t cSizeOfByte = 256;
 constexpr uint32_t cSizeOfAllChildren = 10'000;
 constexpr uint32_t cNullSymbol = 10'000'000;
@@ -1,7 +1,7 @@
 #include "LALR1Parser.hpp"
 
 namespace log_surgeon {
-MatchedSymbol NonTerminal::m_all_children[cSizeOfAllChildren];
+std::vector<MatchedSymbol> NonTerminal::m_all_children(cSizeOfAllChildren);
 
 ParserAST::~ParserAST() = default;
 
@@ -12,5 +12,8 @@ NonTerminal::NonTerminal(Production* p)
           m_production(p),
           m_ast(nullptr) {
     NonTerminal::m_next_children_start += p->m_body.size();
+    if (NonTerminal::m_next_children_start > NonTerminal::m_all_children.size()) {
+        NonTerminal::m_all_children.resize(2 * NonTerminal::m_all_children.size());
+    }
 }
 }  // namespace log_surgeon
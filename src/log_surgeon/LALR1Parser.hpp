@@ -76,7 +76,7 @@ public:
      * @return Token*
      */
     [[nodiscard]] auto token_cast(uint32_t i) const -> Token* {
-        assert(i < cSizeOfAllChildren);
+        assert(m_children_start + i < NonTerminal::m_all_children.size());
         return &std::get<Token>(NonTerminal::m_all_children[m_children_start + i]);
     }
 
@@ -88,7 +88,7 @@ public:
      * @return NonTerminal*
      */
     [[nodiscard]] auto non_terminal_cast(uint32_t i) const -> NonTerminal* {
-        assert(i < cSizeOfAllChildren);
+        assert(m_children_start + i < NonTerminal::m_all_children.size());
         return &std::get<NonTerminal>(NonTerminal::m_all_children[m_children_start + i]);
     }
 
@@ -99,7 +99,9 @@ public:
      */
     auto get_parser_ast() -> std::unique_ptr<ParserAST>& { return m_ast; }
 
-    static MatchedSymbol m_all_children[];
+    // Grows (but never shrinks) as needed, so pointers into it are only valid
+    // until the next NonTerminal is constructed
+    static std::vector<MatchedSymbol> m_all_children;
     static uint32_t m_next_children_start;
     uint32_t m_children_start;
     Production* m_production;
@@ -542,7 +542,8 @@ auto LALR1Parser<NFAStateType, DFAStateType>::get_input_after_last_newline(
                         },
                         [&parse_stack_matches](NonTerminal& m) {
                             for (size_t i = 0; i < m.m_production->m_body.size(); i++) {
-                                assert(m.m_children_start + i < cSizeOfAllChildren);
+                                assert(m.m_children_start + i
+                                       < NonTerminal::m_all_children.size());
                                 parse_stack_matches.push(std::move(
                                         NonTerminal::m_all_children[m.m_children_start + i]
                                 ));
@@ -650,6 +651,8 @@ auto LALR1Parser<NFAStateType, DFAStateType>::parse(Reader& reader) -> NonTermin
 
 template <typename NFAStateType, typename DFAStateType>
 void LALR1Parser<NFAStateType, DFAStateType>::reset() {
+    // The children of a previous parse are no longer needed
+    NonTerminal::m_next_children_start = 0;
     m_next_token = std::nullopt;
     while (!m_parse_stack_states.empty()) {
         m_parse_stack_states.pop();
@@ -724,7 +727,7 @@ auto LALR1Parser<NFAStateType, DFAStateType>::parse_symbol(
                         for (size_t i = 0; i < n; i++) {
                             m_parse_stack_states.pop();
                             assert((matched_non_terminal.m_children_start + n - i - 1)
-                                   < cSizeOfAllChildren);
+                                   < NonTerminal::m_all_children.size());
                             NonTerminal::m_all_children
                                     [matched_non_terminal.m_children_start + n - i - 1]
                                     = std::move(m_parse_stack_matches.top());
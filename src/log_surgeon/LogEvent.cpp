@@ -1,7 +1,10 @@
 #include "LogEvent.hpp"
 
+#include <cstdint>
 #include <memory>
+#include <optional>
 #include <string>
+#include <string_view>
 #include <vector>
 
 #include <log_surgeon/Constants.hpp>
@@ -10,6 +13,45 @@
 #include <log_surgeon/Token.hpp>
 
 namespace log_surgeon {
+namespace {
+    constexpr uint64_t cFnvOffsetBasis = 0xcbf2'9ce4'8422'2325;
+    constexpr uint64_t cFnvPrime = 0x100'0000'01b3;
+
+    /**
+     * @param hash
+     * @param byte
+     * @return hash updated with byte using FNV-1a
+     */
+    auto hash_byte(uint64_t hash, unsigned char byte) -> uint64_t {
+        return (hash ^ byte) * cFnvPrime;
+    }
+
+    /**
+     * @param hash
+     * @param value
+     * @return hash updated with the 8 bytes of value in little-endian order
+     */
+    auto hash_integer(uint64_t hash, uint64_t value) -> uint64_t {
+        for (uint32_t i = 0; i < sizeof(value); i++) {
+            hash = hash_byte(hash, (value >> (i * 8)) & 0xFF);
+        }
+        return hash;
+    }
+
+    /**
+     * @param hash
+     * @param field
+     * @return hash updated with the length of field and then its bytes
+     */
+    auto hash_field(uint64_t hash, std::string_view field) -> uint64_t {
+        hash = hash_integer(hash, field.size());
+        for (char const c : field) {
+            hash = hash_byte(hash, static_cast<unsigned char>(c));
+        }
+        return hash;
+    }
+}  // namespace
+
 LogEventView::LogEventView(LogParser const& log_parser)
         : m_log_parser{log_parser},
           m_log_var_occurrences{log_parser.m_lexer.m_id_symbol.size()} {
@@ -66,6 +108,24 @@ auto LogEventView::get_logtype() const -> std::string {
     return logtype;
 }
 
+auto LogEventView::get_fingerprint(std::vector<std::string> const& var_names) const -> uint64_t {
+    uint64_t hash = hash_field(cFnvOffsetBasis, get_logtype());
+    for (auto const& var_name : var_names) {
+        hash = hash_field(hash, var_name);
+        std::optional<uint32_t> const var_id = m_log_parser.get_symbol_id(var_name);
+        if (false == var_id.has_value()) {
+            hash = hash_integer(hash, 0);
+            continue;
+        }
+        std::vector<Token*> const& occurrences = get_variables(var_id.value());
+        hash = hash_integer(hash, occurrences.size());
+        for (Token* token : occurrences) {
+            hash = hash_field(hash, token->to_string_view().substr(1));
+        }
+    }
+    return hash;
+}
+
 LogEvent::LogEvent(LogEventView const& src) : LogEventView{src.get_log_parser()} {
     set_multiline(src.is_multiline());
     m_log_output_buffer->set_has_timestamp(src.m_log_output_buffer->has_timestamp());
@@ -1,6 +1,7 @@
 #ifndef LOG_SURGEON_LOG_EVENT_HPP
 #define LOG_SURGEON_LOG_EVENT_HPP
 
+#include <cstdint>
 #include <memory>
 #include <string>
 #include <vector>
@@ -101,6 +102,24 @@ public:
      */
     auto get_logtype() const -> std::string;
 
+    /**
+     * Computes a hash of the log event's logtype and the values of the given
+     * variables. It only depends on those bytes, so it's the same on every
+     * platform and can be used to shard or deduplicate events. The hash is
+     * 64-bit FNV-1a over the following fields, where each field is written as
+     * its length (8 bytes, little-endian) followed by its bytes:
+     * - the logtype (see get_logtype)
+     * - for each name in var_names (in order): the name, then the number of
+     *   occurrences of the variable (8 bytes, little-endian), then the value of
+     *   each occurrence without its leading delimiter (which is part of the
+     *   logtype)
+     * A name that isn't in the schema has no occurrences.
+     * @param var_names The names of the variables whose values to include
+     * @return The fingerprint of the log event
+     */
+    [[nodiscard]] auto get_fingerprint(std::vector<std::string> const& var_names) const
+            -> uint64_t;
+
     /**
      * Adds a Token to the array of tokens of a particular token type.
      * @param token_type_id The ID of the variable/token type that token_ptr
@@ -137,10 +137,13 @@ void LogParser::add_rules(std::unique_ptr<SchemaAST> schema_ast) {
                     + arrows + "\n"
             );
         }
+        // an anchored rule can only follow a newline or the start of the input,
+        // rather than any delimiter
+        vector<uint32_t> const line_start_characters{'\n', utf8::cCharStartOfFile};
         unique_ptr<RegexASTGroup<RegexNFAByteState>> delimiter_group
-                = make_unique<RegexASTGroup<RegexNFAByteState>>(
-                        RegexASTGroup<RegexNFAByteState>(delimiters)
-                );
+                = make_unique<RegexASTGroup<RegexNFAByteState>>(RegexASTGroup<RegexNFAByteState>(
+                        rule->m_is_anchored_to_line_start ? line_start_characters : delimiters
+                ));
         rule->m_regex_ptr = make_unique<RegexASTCat<RegexNFAByteState>>(
                 std::move(delimiter_group),
                 std::move(rule->m_regex_ptr)
@@ -1,6 +1,10 @@
 #include "Schema.hpp"
 
+#include <stdexcept>
 #include <string>
+#include <vector>
+
+#include <log_surgeon/Lexer.hpp>
 
 namespace log_surgeon {
 Schema::Schema() {
@@ -10,10 +14,63 @@ Schema::Schema() {
 Schema::Schema(std::string const& schema_file_path)
         : m_schema_ast{SchemaParser::try_schema_file(schema_file_path)} {}
 
-auto Schema::add_variable(std::string const& var_name, std::string const& regex, int priority)
-        -> void {
+auto Schema::add_variable(
+        std::string const& var_name,
+        std::string const& regex,
+        int priority,
+        bool case_insensitive
+) -> void {
     std::string unparsed_string = var_name + ":" + regex;
     std::unique_ptr<SchemaAST> schema_ast = SchemaParser::try_schema_string(unparsed_string);
+    if (case_insensitive) {
+        auto* schema_var_ast = dynamic_cast<SchemaVarAST*>(schema_ast->m_schema_vars[0].get());
+        schema_var_ast->m_regex_ptr->make_case_insensitive();
+    }
     m_schema_ast->add_schema_var(std::move(schema_ast->m_schema_vars[0]), priority);
+    m_variable_dfas.erase(var_name);
+}
+
+auto Schema::match_variable(std::string const& var_name, std::string_view input) -> bool {
+    auto dfa = m_variable_dfas.find(var_name);
+    if (m_variable_dfas.end() == dfa) {
+        dfa = m_variable_dfas.emplace(var_name, build_variable_dfa(var_name)).first;
+    }
+    return dfa->second->matches(input);
+}
+
+auto Schema::build_variable_dfa(std::string const& var_name) const
+        -> std::unique_ptr<finite_automata::RegexDFA<finite_automata::RegexDFAByteState>> {
+    std::vector<uint32_t> delimiters;
+    for (auto const& parser_ast : m_schema_ast->m_delimiters) {
+        auto const* delimiter_string_ast = dynamic_cast<DelimiterStringAST*>(parser_ast.get());
+        delimiters.insert(
+                delimiters.end(),
+                delimiter_string_ast->m_delimiters.begin(),
+                delimiter_string_ast->m_delimiters.end()
+        );
+    }
+    finite_automata::RegexNFA<finite_automata::RegexNFAByteState> nfa;
+    bool has_variable = false;
+    for (auto const& parser_ast : m_schema_ast->m_schema_vars) {
+        auto const* schema_var_ast = dynamic_cast<SchemaVarAST*>(parser_ast.get());
+        if (var_name != schema_var_ast->m_name) {
+            continue;
+        }
+        std::unique_ptr<finite_automata::RegexAST<finite_automata::RegexNFAByteState>> regex(
+                schema_var_ast->m_regex_ptr->clone()
+        );
+        // Like LogParser, only transform '.' into any non-delimiter character
+        // for variables other than timestamps
+        if ("timestamp" != var_name) {
+            regex->remove_delimiters_from_wildcard(delimiters);
+        }
+        lexers::ByteLexer::Rule rule(0, std::move(regex));
+        rule.add_ast(&nfa);
+        has_variable = true;
+    }
+    if (false == has_variable) {
+        throw std::runtime_error("Schema: there is no variable named '" + var_name + "'.");
+    }
+    return lexers::ByteLexer::nfa_to_dfa(nfa);
 }
 }  // namespace log_surgeon
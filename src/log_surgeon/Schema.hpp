@@ -1,9 +1,12 @@
 #ifndef LOG_SURGEON_SCHEMA_HPP
 #define LOG_SURGEON_SCHEMA_HPP
 
+#include <map>
 #include <memory>
 #include <string>
+#include <string_view>
 
+#include <log_surgeon/finite_automata/RegexDFA.hpp>
 #include <log_surgeon/SchemaParser.hpp>
 
 namespace log_surgeon {
@@ -26,8 +29,27 @@ public:
      * @param var_name
      * @param regex
      * @param priority
+     * @param case_insensitive Whether letters in regex should match either case
      */
-    auto add_variable(std::string const& var_name, std::string const& regex, int priority) -> void;
+    auto add_variable(
+            std::string const& var_name,
+            std::string const& regex,
+            int priority,
+            bool case_insensitive = false
+    ) -> void;
+
+    /**
+     * Checks whether the whole of input matches the variable var_name the way
+     * the log lexer would match it (i.e., `.` doesn't match the schema's
+     * delimiters unless var_name is timestamp). If there are multiple
+     * variables named var_name, input only needs to match one of them. The
+     * variable's DFA is built the first time it's matched and then cached.
+     * @param var_name
+     * @param input
+     * @return Whether input matches the variable
+     * @throw std::runtime_error if the schema has no variable named var_name
+     */
+    auto match_variable(std::string const& var_name, std::string_view input) -> bool;
 
     /* Work in progress API to modify a schema object
 
@@ -65,11 +87,25 @@ public:
     [[nodiscard]] auto release_schema_ast_ptr() -> std::unique_ptr<SchemaAST> {
         auto old_schema_ast = std::move(m_schema_ast);
         m_schema_ast = std::make_unique<SchemaAST>();
+        m_variable_dfas.clear();
         return old_schema_ast;
     }
 
 private:
+    /**
+     * @param var_name
+     * @return A DFA accepting the variables named var_name
+     * @throw std::runtime_error if the schema has no variable named var_name
+     */
+    [[nodiscard]] auto build_variable_dfa(std::string const& var_name) const
+            -> std::unique_ptr<finite_automata::RegexDFA<finite_automata::RegexDFAByteState>>;
+
     std::unique_ptr<SchemaAST> m_schema_ast;
+    // DFAs built by match_variable, keyed by variable name
+    std::map<
+            std::string,
+            std::unique_ptr<finite_automata::RegexDFA<finite_automata::RegexDFAByteState>>>
+            m_variable_dfas;
 };
 }  // namespace log_surgeon
 
@@ -1,9 +1,11 @@
 #include "SchemaParser.hpp"
 
-#include <cmath>
+#include <cctype>
+#include <map>
 #include <memory>
 #include <span>
 #include <stdexcept>
+#include <vector>
 
 #include <log_surgeon/Constants.hpp>
 #include <log_surgeon/FileReader.hpp>
@@ -32,7 +34,7 @@ using std::string;
 using std::unique_ptr;
 
 namespace log_surgeon {
-SchemaParser::SchemaParser() {
+SchemaParser::SchemaParser(RegexLimits const& limits) : m_limits(limits) {
     add_lexical_rules();
     add_productions();
     generate();
@@ -46,7 +48,8 @@ auto SchemaParser::generate_schema_ast(Reader& reader) -> unique_ptr<SchemaAST>
     return schema_ast;
 }
 
-auto SchemaParser::try_schema_file(string const& schema_file_path) -> unique_ptr<SchemaAST> {
+auto SchemaParser::try_schema_file(string const& schema_file_path, RegexLimits const& limits)
+        -> unique_ptr<SchemaAST> {
     FileReader schema_reader;
     ErrorCode error_code = schema_reader.try_open(schema_file_path);
     if (ErrorCode::Success != error_code) {
@@ -60,7 +63,7 @@ auto SchemaParser::try_schema_file(string const& schema_file_path) -> unique_ptr
                 strfmt("Failed to read '%s', error_code=%d", schema_file_path.c_str(), code)
         );
     }
-    SchemaParser sp;
+    SchemaParser sp(limits);
     Reader reader{[&](char* buf, size_t count, size_t& read_to) -> ErrorCode {
         schema_reader.read(buf, count, read_to);
         if (read_to == 0) {
@@ -73,7 +76,8 @@ auto SchemaParser::try_schema_file(string const& schema_file_path) -> unique_ptr
     return schema_ast;
 }
 
-auto SchemaParser::try_schema_string(string const& schema_string) -> unique_ptr<SchemaAST> {
+auto SchemaParser::try_schema_string(string const& schema_string, RegexLimits const& limits)
+        -> unique_ptr<SchemaAST> {
     Reader reader{[&](char* dst_buf, size_t count, size_t& read_to) -> ErrorCode {
         uint32_t unparsed_string_pos = 0;
         std::span<char> const buf{dst_buf, count};
@@ -90,10 +94,18 @@ auto SchemaParser::try_schema_string(string const& schema_string) -> unique_ptr<
         unparsed_string_pos += count;
         return ErrorCode::Success;
     }};
-    SchemaParser sp;
+    SchemaParser sp(limits);
     return sp.generate_schema_ast(reader);
 }
 
+auto SchemaParser::escape_regex(std::string_view text) -> std::string {
+    string pattern;
+    for (char const character : text) {
+        pattern += RegexASTLiteralByte(static_cast<unsigned char>(character)).serialize();
+    }
+    return pattern;
+}
+
 static auto new_identifier_rule(NonTerminal* m) -> unique_ptr<IdentifierAST> {
     string r1 = m->token_cast(0)->to_string();
     return make_unique<IdentifierAST>(IdentifierAST(r1[0]));
@@ -107,11 +119,59 @@ static auto existing_identifier_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
     return std::move(r1);
 }
 
-static auto schema_var_rule(NonTerminal* m) -> unique_ptr<SchemaVarAST> {
+auto SchemaParser::schema_var_rule(NonTerminal* m, bool is_anchored_to_line_start)
+        -> unique_ptr<SchemaVarAST> {
     auto* r2 = dynamic_cast<IdentifierAST*>(m->non_terminal_cast(1)->get_parser_ast().get());
     Token* colon_token = m->token_cast(2);
-    auto& r4 = m->non_terminal_cast(3)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
-    return make_unique<SchemaVarAST>(r2->m_name, std::move(r4), colon_token->m_line);
+    // The regex follows the `^` if there is one
+    uint32_t const regex_pos = is_anchored_to_line_start ? 4 : 3;
+    auto& regex
+            = m->non_terminal_cast(regex_pos)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
+    auto schema_var_ast
+            = make_unique<SchemaVarAST>(r2->m_name, std::move(regex), colon_token->m_line);
+    schema_var_ast->m_is_anchored_to_line_start = is_anchored_to_line_start;
+    // The pattern spans from the colon to the lookahead token, which may have
+    // wrapped around the input buffer
+    uint32_t const pattern_start = colon_token->m_end_pos;
+    uint32_t const pattern_end = m_next_token->m_start_pos;
+    uint32_t pattern_length = pattern_end - pattern_start;
+    if (pattern_end < pattern_start) {
+        pattern_length = m_next_token->m_buffer_size - pattern_start + pattern_end;
+    }
+    check_regex_limits(*schema_var_ast, pattern_length);
+    // Everything on the parse stack has been reduced to an AST, so the
+    // children are no longer needed and their buffer can be reused
+    NonTerminal::m_next_children_start = 0;
+    return schema_var_ast;
+}
+
+auto SchemaParser::check_regex_limits(SchemaVarAST const& schema_var_ast, uint32_t pattern_length)
+        const -> void {
+    string const error_prefix = "RegexLimits: schema variable '" + schema_var_ast.m_name
+                                + "' on line " + std::to_string(schema_var_ast.m_line_num + 1);
+    if (pattern_length > m_limits.m_max_pattern_length) {
+        throw std::runtime_error(
+                error_prefix + " has a pattern of " + std::to_string(pattern_length)
+                + " characters, which is more than the maximum of "
+                + std::to_string(m_limits.m_max_pattern_length) + "."
+        );
+    }
+    uint32_t const depth = schema_var_ast.m_regex_ptr->get_depth();
+    if (depth > m_limits.m_max_ast_depth) {
+        throw std::runtime_error(
+                error_prefix + " has a pattern nested " + std::to_string(depth)
+                + " levels deep, which is more than the maximum of "
+                + std::to_string(m_limits.m_max_ast_depth) + "."
+        );
+    }
+    uint64_t const expanded_size = schema_var_ast.m_regex_ptr->get_expanded_size();
+    if (expanded_size > m_limits.m_max_expanded_size) {
+        throw std::runtime_error(
+                error_prefix + " has a pattern that expands to more than "
+                + std::to_string(m_limits.m_max_expanded_size)
+                + " literals and groups once its repetitions are unrolled."
+        );
+    }
 }
 
 static auto new_schema_rule(NonTerminal* /* m */) -> unique_ptr<SchemaAST> {
@@ -145,10 +205,6 @@ auto SchemaParser::existing_schema_rule(NonTerminal* m) -> unique_ptr<SchemaAST>
     std::unique_ptr<SchemaAST> schema_ast(dynamic_cast<SchemaAST*>(r1.release()));
     unique_ptr<ParserAST>& r2 = m->non_terminal_cast(2)->get_parser_ast();
     schema_ast->add_schema_var(std::move(r2));
-    // Can reset the buffers at this point and allow reading
-    if (NonTerminal::m_next_children_start > cSizeOfAllChildren / 2) {
-        NonTerminal::m_next_children_start = 0;
-    }
     return schema_ast;
 }
 
@@ -196,13 +252,39 @@ static auto regex_match_one_or_more_rule(NonTerminal* m) -> unique_ptr<ParserAST
     ));
 }
 
-static auto regex_match_exactly_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
+/**
+ * @param integer_ast
+ * @param limits
+ * @return The value of integer_ast as a repetition count
+ * @throw std::runtime_error if the value is greater than the limit
+ */
+static auto get_repetition_count(RegexASTIntegerByte const* integer_ast, RegexLimits const& limits)
+        -> uint32_t {
+    uint64_t count = 0;
+    for (uint32_t const digit : integer_ast->get_digits()) {
+        count = count * 10 + digit;
+        if (count > limits.m_max_repetition_count) {
+            throw std::runtime_error(
+                    "RegexRepetition: repetition count " + integer_ast->serialize()
+                    + " is greater than the maximum of "
+                    + std::to_string(limits.m_max_repetition_count) + "."
+            );
+        }
+    }
+    return static_cast<uint32_t>(count);
+}
+
+static auto regex_match_exactly_rule(NonTerminal* m, RegexLimits const& limits)
+        -> unique_ptr<ParserAST> {
     auto& r3 = m->non_terminal_cast(2)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
     auto* r3_ptr = dynamic_cast<RegexASTIntegerByte*>(r3.get());
-    uint32_t reps = 0;
-    uint32_t r3_size = r3_ptr->get_digits().size();
-    for (uint32_t i = 0; i < r3_size; i++) {
-        reps += r3_ptr->get_digit(i) * (uint32_t)pow(10, r3_size - i - 1);
+    uint32_t reps = get_repetition_count(r3_ptr, limits);
+    // A max of 0 means there is no upper bound, so a{0} can't be represented
+    if (0 == reps) {
+        throw std::runtime_error(
+                "RegexRepetition: '{" + r3_ptr->serialize()
+                + "}' is not a valid repetition, the count must be at least 1."
+        );
     }
     auto& r1 = m->non_terminal_cast(0)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
     return unique_ptr<ParserAST>(new ParserValueRegex(
@@ -210,20 +292,21 @@ static auto regex_match_exactly_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
     ));
 }
 
-static auto regex_match_range_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
+static auto regex_match_range_rule(NonTerminal* m, RegexLimits const& limits)
+        -> unique_ptr<ParserAST> {
     auto& r3 = m->non_terminal_cast(2)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
     auto* r3_ptr = dynamic_cast<RegexASTIntegerByte*>(r3.get());
-    uint32_t min = 0;
-    uint32_t r3_size = r3_ptr->get_digits().size();
-    for (uint32_t i = 0; i < r3_size; i++) {
-        min += r3_ptr->get_digit(i) * (uint32_t)pow(10, r3_size - i - 1);
-    }
+    uint32_t min = get_repetition_count(r3_ptr, limits);
     auto& r5 = m->non_terminal_cast(4)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
     auto* r5_ptr = dynamic_cast<RegexASTIntegerByte*>(r5.get());
-    uint32_t max = 0;
-    uint32_t r5_size = r5_ptr->get_digits().size();
-    for (uint32_t i = 0; i < r5_size; i++) {
-        max += r5_ptr->get_digit(i) * (uint32_t)pow(10, r5_size - i - 1);
+    uint32_t max = get_repetition_count(r5_ptr, limits);
+    // A max of 0 means there is no upper bound, so a{0,0} can't be represented
+    if (0 == max || min > max) {
+        throw std::runtime_error(
+                "RegexRepetition: '{" + r3_ptr->serialize() + "," + r5_ptr->serialize()
+                + "}' is not a valid repetition, the maximum must be at least 1 and no less "
+                  "than the minimum."
+        );
     }
     auto& r1 = m->non_terminal_cast(0)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
     return unique_ptr<ParserAST>(new ParserValueRegex(
@@ -231,6 +314,18 @@ static auto regex_match_range_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
     ));
 }
 
+static auto regex_match_at_least_rule(NonTerminal* m, RegexLimits const& limits)
+        -> unique_ptr<ParserAST> {
+    auto& r3 = m->non_terminal_cast(2)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
+    auto* r3_ptr = dynamic_cast<RegexASTIntegerByte*>(r3.get());
+    uint32_t min = get_repetition_count(r3_ptr, limits);
+    auto& r1 = m->non_terminal_cast(0)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
+    // a max of 0 means there is no upper bound
+    return unique_ptr<ParserAST>(new ParserValueRegex(
+            unique_ptr<RegexASTByte>(new RegexASTMultiplicationByte(std::move(r1), min, 0))
+    ));
+}
+
 static auto regex_add_literal_existing_group_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
     auto& r1 = m->non_terminal_cast(0)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
     auto& r2 = m->non_terminal_cast(1)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
@@ -267,6 +362,81 @@ static auto regex_add_range_new_group_rule(NonTerminal* m) -> unique_ptr<ParserA
     );
 }
 
+static auto regex_add_dot_existing_group_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
+    auto& r1 = m->non_terminal_cast(0)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
+    auto* r1_ptr = dynamic_cast<RegexASTGroupByte*>(r1.get());
+    // '.' is a literal in a bracket expression rather than a wildcard
+    r1_ptr->add_literal('.');
+    return unique_ptr<ParserAST>(new ParserValueRegex(std::move(r1)));
+}
+
+static auto regex_add_dot_new_group_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
+    // '.' is a literal in a bracket expression rather than a wildcard
+    return unique_ptr<ParserAST>(new ParserValueRegex(make_unique<RegexASTGroupByte>('.', '.')));
+}
+
+static auto regex_add_dot_and_dash_existing_group_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
+    auto& r1 = m->non_terminal_cast(0)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
+    auto* r1_ptr = dynamic_cast<RegexASTGroupByte*>(r1.get());
+    r1_ptr->add_literal('.');
+    r1_ptr->add_literal('-');
+    return unique_ptr<ParserAST>(new ParserValueRegex(std::move(r1)));
+}
+
+static auto regex_add_dot_and_dash_new_group_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
+    auto regex_ast_group = make_unique<RegexASTGroupByte>('.', '.');
+    regex_ast_group->add_literal('-');
+    return unique_ptr<ParserAST>(new ParserValueRegex(std::move(regex_ast_group)));
+}
+
+static auto regex_add_dash_new_group_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
+    return unique_ptr<ParserAST>(new ParserValueRegex(make_unique<RegexASTGroupByte>('-', '-')));
+}
+
+static auto regex_add_dash_complement_group_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
+    // default constructs to a m_negate group
+    unique_ptr<RegexASTGroupByte> regex_ast_group = make_unique<RegexASTGroupByte>();
+    regex_ast_group->add_literal('-');
+    return unique_ptr<ParserAST>(new ParserValueRegex(std::move(regex_ast_group)));
+}
+
+/**
+ * @param group
+ * @param item A RegexASTLiteralByte or a (non-negated) RegexASTGroupByte to
+ * add to group
+ * @return A new group containing group's ranges, item, and a literal '-'
+ */
+static auto add_item_and_dash_to_group(RegexASTGroupByte* group, RegexASTByte* item)
+        -> unique_ptr<RegexASTGroupByte> {
+    unique_ptr<RegexASTGroupByte> regex_ast_group;
+    if (auto* literal = dynamic_cast<RegexASTLiteralByte*>(item); nullptr != literal) {
+        regex_ast_group = make_unique<RegexASTGroupByte>(group, literal);
+    } else {
+        regex_ast_group
+                = make_unique<RegexASTGroupByte>(group, dynamic_cast<RegexASTGroupByte*>(item));
+    }
+    regex_ast_group->add_literal('-');
+    return regex_ast_group;
+}
+
+static auto regex_add_item_and_dash_existing_group_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
+    auto& r1 = m->non_terminal_cast(0)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
+    auto& r2 = m->non_terminal_cast(1)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
+    auto* r1_ptr = dynamic_cast<RegexASTGroupByte*>(r1.get());
+    return unique_ptr<ParserAST>(
+            new ParserValueRegex(add_item_and_dash_to_group(r1_ptr, r2.get()))
+    );
+}
+
+static auto regex_add_item_and_dash_new_group_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
+    auto& r2 = m->non_terminal_cast(1)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
+    // an empty group that isn't negated
+    RegexASTGroupByte empty_group(std::vector<uint32_t>{});
+    return unique_ptr<ParserAST>(
+            new ParserValueRegex(add_item_and_dash_to_group(&empty_group, r2.get()))
+    );
+}
+
 static auto regex_complement_incomplete_group_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
     return unique_ptr<ParserAST>(new ParserValueRegex(make_unique<RegexASTGroupByte>()));
 }
@@ -281,6 +451,38 @@ static auto regex_range_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
     );
 }
 
+static auto regex_posix_class_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
+    auto* r3 = dynamic_cast<IdentifierAST*>(m->non_terminal_cast(2)->get_parser_ast().get());
+    // TODO: Handle UTF-8 (multi-byte transitions) as well
+    static std::map<string, std::vector<RegexASTGroupByte::Range>> const cPosixClasses
+            = {{"alnum", {{'0', '9'}, {'A', 'Z'}, {'a', 'z'}}},
+               {"alpha", {{'A', 'Z'}, {'a', 'z'}}},
+               {"blank", {{' ', ' '}, {'\t', '\t'}}},
+               {"cntrl", {{0x00, 0x1F}, {0x7F, 0x7F}}},
+               {"digit", {{'0', '9'}}},
+               {"graph", {{'!', '~'}}},
+               {"lower", {{'a', 'z'}}},
+               {"print", {{' ', '~'}}},
+               {"punct", {{'!', '/'}, {':', '@'}, {'[', '`'}, {'{', '~'}}},
+               {"space", {{' ', ' '}, {'\t', '\r'}}},
+               {"upper", {{'A', 'Z'}}},
+               {"xdigit", {{'0', '9'}, {'A', 'F'}, {'a', 'f'}}}};
+    auto const posix_class = cPosixClasses.find(r3->m_name);
+    if (cPosixClasses.end() == posix_class) {
+        throw std::runtime_error(
+                "PosixClass: '[:" + r3->m_name + ":]' is not a supported character class."
+        );
+    }
+    auto regex_posix_class = make_unique<RegexASTGroupByte>(
+            posix_class->second[0].first,
+            posix_class->second[0].second
+    );
+    for (size_t i = 1; i < posix_class->second.size(); i++) {
+        regex_posix_class->add_range(posix_class->second[i].first, posix_class->second[i].second);
+    }
+    return unique_ptr<ParserAST>(new ParserValueRegex(std::move(regex_posix_class)));
+}
+
 static auto regex_middle_identity_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
     return unique_ptr<ParserAST>(new ParserValueRegex(
             std::move(m->non_terminal_cast(1)->get_parser_ast()->get<unique_ptr<RegexASTByte>>())
@@ -303,6 +505,44 @@ static auto regex_cancel_literal_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
     ));
 }
 
+static auto regex_hex_escape_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
+    string const hex_digits = m->token_cast(2)->to_string() + m->token_cast(3)->to_string();
+    if (0 == std::isxdigit(hex_digits[0]) || 0 == std::isxdigit(hex_digits[1])) {
+        throw std::runtime_error(
+                "HexEscape: '\\x" + hex_digits
+                + "' is not a valid escape, '\\x' must be followed by two hex digits."
+        );
+    }
+    return unique_ptr<ParserAST>(new ParserValueRegex(
+            unique_ptr<RegexASTByte>(new RegexASTLiteralByte(std::stoul(hex_digits, nullptr, 16)))
+    ));
+}
+
+/**
+ * @param m
+ * @param num_digits The number of octal digits following the backslash
+ * @return The literal the octal escape represents
+ */
+static auto regex_octal_escape_rule(NonTerminal* m, uint32_t num_digits) -> unique_ptr<ParserAST> {
+    string octal_digits;
+    for (uint32_t i = 1; i <= num_digits; i++) {
+        octal_digits += m->token_cast(i)->to_string();
+    }
+    uint32_t value = 0;
+    for (char const digit : octal_digits) {
+        value = value * 8 + (digit - '0');
+    }
+    if (octal_digits.find_first_of("89") != string::npos || value >= cSizeOfByte) {
+        throw std::runtime_error(
+                "OctalEscape: '\\" + octal_digits
+                + "' is not a valid escape, octal escapes must be between \\0 and \\377."
+        );
+    }
+    return unique_ptr<ParserAST>(
+            new ParserValueRegex(unique_ptr<RegexASTByte>(new RegexASTLiteralByte(value)))
+    );
+}
+
 static auto regex_existing_integer_rule(NonTerminal* m) -> unique_ptr<ParserAST> {
     auto& r2 = m->non_terminal_cast(0)->get_parser_ast()->get<unique_ptr<RegexASTByte>>();
     auto* r2_ptr = dynamic_cast<RegexASTIntegerByte*>(r2.get());
@@ -328,7 +568,12 @@ static auto regex_digit_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
 }
 
 static auto regex_wildcard_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
-    unique_ptr<RegexASTGroupByte> regex_wildcard = make_unique<RegexASTGroupByte>(0, cUnicodeMax);
+    // an empty group that isn't negated
+    auto regex_wildcard = make_unique<RegexASTGroupByte>(std::vector<uint32_t>{});
+    // the complement of nothing is every character except the UTF-16 surrogates
+    for (auto const& [begin, end] : RegexASTGroupByte::complement({})) {
+        regex_wildcard->add_range(begin, end);
+    }
     regex_wildcard->set_is_wildcard_true();
     return unique_ptr<ParserAST>(new ParserValueRegex(std::move(regex_wildcard)));
 }
@@ -363,6 +608,16 @@ static auto regex_newline_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
     );
 }
 
+static auto regex_word_character_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
+    unique_ptr<RegexASTGroupByte> regex_ast_group = make_unique<RegexASTGroupByte>('a', 'z');
+    regex_ast_group->add_range('A', 'Z');
+    regex_ast_group->add_range('0', '9');
+    regex_ast_group->add_literal('_');
+    return unique_ptr<ParserAST>(
+            new ParserValueRegex(unique_ptr<RegexASTByte>(std::move(regex_ast_group)))
+    );
+}
+
 static auto regex_white_space_rule(NonTerminal* /* m */) -> unique_ptr<ParserAST> {
     unique_ptr<RegexASTGroupByte> regex_ast_group = make_unique<RegexASTGroupByte>(
             RegexASTGroupByte({' ', '\t', '\r', '\n', '\v', '\f'})
@@ -427,6 +682,8 @@ void SchemaParser::add_lexical_rules() {
     add_token("t", 't');
     add_token("f", 'f');
     add_token("v", 'v');
+    add_token("w", 'w');
+    add_token("x", 'x');
     add_token_chain("Delimiters", "delimiters");
     // default constructs to a m_negate group
     unique_ptr<RegexASTGroupByte> comment_characters = make_unique<RegexASTGroupByte>();
@@ -476,7 +733,12 @@ void SchemaParser::add_productions() {
     add_production(
             "SchemaVar",
             {"WhitespaceStar", "Identifier", "Colon", "Regex"},
-            schema_var_rule
+            [this](NonTerminal* m) { return schema_var_rule(m, false); }
+    );
+    add_production(
+            "SchemaVar",
+            {"WhitespaceStar", "Identifier", "Colon", "Hat", "Regex"},
+            [this](NonTerminal* m) { return schema_var_rule(m, true); }
     );
     add_production("Identifier", {"Identifier", "AlphaNumeric"}, existing_identifier_rule);
     add_production("Identifier", {"AlphaNumeric"}, new_identifier_rule);
@@ -496,26 +758,57 @@ void SchemaParser::add_productions() {
     add_production(
             "MatchExact",
             {"CompleteGroup", "Lbrace", "Integer", "Rbrace"},
-            regex_match_exactly_rule
+            [this](NonTerminal* m) { return regex_match_exactly_rule(m, m_limits); }
     );
     add_production(
             "MatchRange",
             {"CompleteGroup", "Lbrace", "Integer", "Comma", "Integer", "Rbrace"},
-            regex_match_range_rule
+            [this](NonTerminal* m) { return regex_match_range_rule(m, m_limits); }
+    );
+    add_production(
+            "MatchRange",
+            {"CompleteGroup", "Lbrace", "Integer", "Comma", "Rbrace"},
+            [this](NonTerminal* m) { return regex_match_at_least_rule(m, m_limits); }
     );
     add_production("CompleteGroup", {"IncompleteGroup", "Rbracket"}, regex_identity_rule);
+    add_production(
+            "CompleteGroup",
+            {"IncompleteGroup", "Literal", "Dash", "Rbracket"},
+            regex_add_item_and_dash_existing_group_rule
+    );
+    add_production(
+            "CompleteGroup",
+            {"IncompleteGroup", "GroupRange", "Dash", "Rbracket"},
+            regex_add_item_and_dash_existing_group_rule
+    );
+    add_production(
+            "CompleteGroup",
+            {"Lbracket", "Literal", "Dash", "Rbracket"},
+            regex_add_item_and_dash_new_group_rule
+    );
+    add_production(
+            "CompleteGroup",
+            {"Lbracket", "GroupRange", "Dash", "Rbracket"},
+            regex_add_item_and_dash_new_group_rule
+    );
+    add_production(
+            "CompleteGroup",
+            {"IncompleteGroup", "Dot", "Dash", "Rbracket"},
+            regex_add_dot_and_dash_existing_group_rule
+    );
+    add_production(
+            "CompleteGroup",
+            {"Lbracket", "Dot", "Dash", "Rbracket"},
+            regex_add_dot_and_dash_new_group_rule
+    );
     add_production("CompleteGroup", {"Literal"}, regex_identity_rule);
     add_production("CompleteGroup", {"Digit"}, regex_identity_rule);
     add_production("CompleteGroup", {"Wildcard"}, regex_identity_rule);
     add_production("CompleteGroup", {"WhiteSpace"}, regex_identity_rule);
+    add_production("CompleteGroup", {"WordCharacter"}, regex_identity_rule);
     add_production(
             "IncompleteGroup",
-            {"IncompleteGroup", "LiteralRange"},
-            regex_add_range_existing_group_rule
-    );
-    add_production(
-            "IncompleteGroup",
-            {"IncompleteGroup", "Digit"},
+            {"IncompleteGroup", "GroupRange"},
             regex_add_range_existing_group_rule
     );
     add_production(
@@ -525,20 +818,49 @@ void SchemaParser::add_productions() {
     );
     add_production(
             "IncompleteGroup",
-            {"IncompleteGroup", "WhiteSpace"},
-            regex_add_literal_existing_group_rule
+            {"IncompleteGroup", "Dot"},
+            regex_add_dot_existing_group_rule
     );
-    add_production("IncompleteGroup", {"Lbracket", "LiteralRange"}, regex_add_range_new_group_rule);
-    add_production("IncompleteGroup", {"Lbracket", "Digit"}, regex_add_range_new_group_rule);
+    add_production("IncompleteGroup", {"Lbracket", "GroupRange"}, regex_add_range_new_group_rule);
     add_production("IncompleteGroup", {"Lbracket", "Literal"}, regex_add_literal_new_group_rule);
-    add_production("IncompleteGroup", {"Lbracket", "WhiteSpace"}, regex_add_literal_new_group_rule);
+    add_production("IncompleteGroup", {"Lbracket", "Dot"}, regex_add_dot_new_group_rule);
+    add_production("IncompleteGroup", {"Lbracket", "Dash"}, regex_add_dash_new_group_rule);
     add_production("IncompleteGroup", {"Lbracket", "Hat"}, regex_complement_incomplete_group_rule);
+    add_production(
+            "IncompleteGroup",
+            {"Lbracket", "Hat", "Dash"},
+            regex_add_dash_complement_group_rule
+    );
+    add_production("GroupRange", {"LiteralRange"}, regex_identity_rule);
+    add_production("GroupRange", {"Digit"}, regex_identity_rule);
+    add_production("GroupRange", {"WhiteSpace"}, regex_identity_rule);
+    add_production("GroupRange", {"WordCharacter"}, regex_identity_rule);
+    add_production("GroupRange", {"PosixClass"}, regex_identity_rule);
+    add_production(
+            "PosixClass",
+            {"Lbracket", "Colon", "Identifier", "Colon", "Rbracket"},
+            regex_posix_class_rule
+    );
     add_production("LiteralRange", {"Literal", "Dash", "Literal"}, regex_range_rule);
     add_production("Literal", {"Backslash", "t"}, regex_tab_rule);
     add_production("Literal", {"Backslash", "n"}, regex_newline_rule);
     add_production("Literal", {"Backslash", "v"}, regex_vertical_tab_rule);
     add_production("Literal", {"Backslash", "f"}, regex_form_feed_rule);
     add_production("Literal", {"Backslash", "r"}, regex_char_return_rule);
+    add_production(
+            "Literal",
+            {"Backslash", "x", "AlphaNumeric", "AlphaNumeric"},
+            regex_hex_escape_rule
+    );
+    add_production("Literal", {"Backslash", "Numeric"}, [](NonTerminal* m) {
+        return regex_octal_escape_rule(m, 1);
+    });
+    add_production("Literal", {"Backslash", "Numeric", "Numeric"}, [](NonTerminal* m) {
+        return regex_octal_escape_rule(m, 2);
+    });
+    add_production("Literal", {"Backslash", "Numeric", "Numeric", "Numeric"}, [](NonTerminal* m) {
+        return regex_octal_escape_rule(m, 3);
+    });
     add_production("Literal", {"Space"}, regex_literal_rule);
     add_production("Literal", {"Bang"}, regex_literal_rule);
     add_production("Literal", {"Quotation"}, regex_literal_rule);
@@ -583,5 +905,6 @@ void SchemaParser::add_productions() {
     add_production("Digit", {"Backslash", "d"}, regex_digit_rule);
     add_production("Wildcard", {"Dot"}, regex_wildcard_rule);
     add_production("WhiteSpace", {"Backslash", "s"}, regex_white_space_rule);
+    add_production("WordCharacter", {"Backslash", "w"}, regex_word_character_rule);
 }
 }  // namespace log_surgeon
@@ -1,6 +1,8 @@
 #ifndef LOG_SURGEON_SCHEMA_PARSER_HPP
 #define LOG_SURGEON_SCHEMA_PARSER_HPP
 
+#include <string>
+#include <string_view>
 #include <utility>
 
 #include <log_surgeon/LALR1Parser.hpp>
@@ -55,6 +57,9 @@ public:
     uint32_t m_line_num;
     std::string m_name;
     std::unique_ptr<finite_automata::RegexAST<finite_automata::RegexNFAByteState>> m_regex_ptr;
+    // Whether the pattern starts with `^` (i.e., it can only match at the
+    // start of a line)
+    bool m_is_anchored_to_line_start{false};
 };
 
 class DelimiterStringAST : public ParserAST {
@@ -67,6 +72,23 @@ public:
     std::vector<uint32_t> m_delimiters;
 };
 
+/**
+ * Limits on the complexity of a schema's regexes. They're enforced while the
+ * schema is parsed so that a schema can't make the lexer generate an enormous
+ * automaton.
+ */
+struct RegexLimits {
+    // The longest (in characters) a variable's pattern can be
+    uint32_t m_max_pattern_length{1000};
+    // The largest N allowed in a{N}, a{N,M}, and a{N,}
+    uint32_t m_max_repetition_count{1000};
+    // The most literals and groups a variable's pattern can have once every
+    // repetition is expanded (e.g., `(a{1000}){100}` expands to 100,000)
+    uint64_t m_max_expanded_size{10'000};
+    // The deepest a variable's AST can be
+    uint32_t m_max_ast_depth{1000};
+};
+
 class SchemaParser : public LALR1Parser<
                              finite_automata::RegexNFAByteState,
                              finite_automata::RegexDFAByteState> {
@@ -74,24 +96,38 @@ public:
     /**
      * File wrapper around generate_schema_ast()
      * @param schema_file_path
+     * @param limits
      * @return std::unique_ptr<SchemaAST>
+     * @throw std::runtime_error if a regex exceeds any of the limits
      */
-    static auto try_schema_file(std::string const& schema_file_path) -> std::unique_ptr<SchemaAST>;
+    static auto try_schema_file(std::string const& schema_file_path, RegexLimits const& limits = {})
+            -> std::unique_ptr<SchemaAST>;
 
     /**
      * String wrapper around generate_schema_ast()
      * @param schema_string
+     * @param limits
      * @return std::unique_ptr<SchemaAST>
+     * @throw std::runtime_error if a regex exceeds any of the limits
      */
-    static auto try_schema_string(std::string const& schema_string) -> std::unique_ptr<SchemaAST>;
+    static auto try_schema_string(std::string const& schema_string, RegexLimits const& limits = {})
+            -> std::unique_ptr<SchemaAST>;
 
     static auto get_special_regex_characters() -> std::unordered_map<char, std::string> const& {
         return m_special_regex_characters;
     }
 
+    /**
+     * Escapes text so that it only matches itself when used as (part of) a
+     * schema regex (e.g., to add a verbatim string with Schema::add_variable)
+     * @param text
+     * @return The escaped pattern
+     */
+    static auto escape_regex(std::string_view text) -> std::string;
+
 private:
     // Constructor
-    SchemaParser();
+    explicit SchemaParser(RegexLimits const& limits);
 
     /**
      * A semantic rule that needs access to soft_reset()
@@ -100,6 +136,24 @@ private:
      */
     auto existing_schema_rule(NonTerminal* m) -> std::unique_ptr<SchemaAST>;
 
+    /**
+     * A semantic rule that needs access to m_limits and m_next_token
+     * @param m
+     * @param is_anchored_to_line_start Whether the pattern starts with `^`
+     * @return std::unique_ptr<SchemaVarAST>
+     */
+    auto schema_var_rule(NonTerminal* m, bool is_anchored_to_line_start)
+            -> std::unique_ptr<SchemaVarAST>;
+
+    /**
+     * @param schema_var_ast
+     * @param pattern_length
+     * @throw std::runtime_error if schema_var_ast's regex exceeds any of
+     * m_limits
+     */
+    auto check_regex_limits(SchemaVarAST const& schema_var_ast, uint32_t pattern_length) const
+            -> void;
+
     /**
      * After lexing half of the buffer, reads into that half of the buffer and
      * changes variables accordingly
@@ -139,6 +193,8 @@ private:
             {'{', "Lbrace"},
             {'}', "Rbrace"},
             {'|', "Vbar"}};
+
+    RegexLimits m_limits;
 };
 }  // namespace log_surgeon
 
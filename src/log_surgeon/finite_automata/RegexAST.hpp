@@ -5,6 +5,7 @@
 #include <cstdint>
 #include <memory>
 #include <set>
+#include <string>
 #include <utility>
 #include <vector>
 
@@ -38,6 +39,35 @@ public:
      */
     virtual auto remove_delimiters_from_wildcard(std::vector<uint32_t>& delimiters) -> void = 0;
 
+    /**
+     * Makes every letter in a lexer rule also match its other case (e.g., so
+     * `error` matches `ERROR` and `Error` as well)
+     */
+    virtual auto make_case_insensitive() -> void = 0;
+
+    /**
+     * Serializes the node (and its descendants) back into a pattern using the
+     * schema's regex syntax, such that parsing the pattern results in an
+     * equivalent AST. The pattern is canonical rather than the one originally
+     * parsed (e.g., alternatives are always parenthesized).
+     * @return The pattern
+     */
+    [[nodiscard]] virtual auto serialize() const -> std::string = 0;
+
+    /**
+     * Gets the number of literals and groups in the node (and its descendants)
+     * once every repetition is unrolled to its upper bound (or its lower bound
+     * plus one if it's unbounded), which is roughly proportional to the number
+     * of NFA states the node adds. Saturates at UINT64_MAX.
+     * @return The expanded size
+     */
+    [[nodiscard]] virtual auto get_expanded_size() const -> uint64_t = 0;
+
+    /**
+     * @return The number of nodes on the longest path from the node to a leaf
+     */
+    [[nodiscard]] virtual auto get_depth() const -> uint32_t = 0;
+
     /**
      * Add the needed RegexNFA::states to the passed in nfa to handle the
      * current node before transitioning to a pre-tagged end_state
@@ -65,9 +95,7 @@ public:
      * lexer rule containing RegexASTLiteral at a leaf node in its AST
      * @param is_possible_input
      */
-    auto set_possible_inputs_to_true(bool is_possible_input[]) const -> void override {
-        is_possible_input[m_character] = true;
-    }
+    auto set_possible_inputs_to_true(bool is_possible_input[]) const -> void override;
 
     /**
      * Transforms '.' to to be any non-delimiter in a lexer rule, which does
@@ -78,6 +106,26 @@ public:
         // Do nothing
     }
 
+    /**
+     * Makes the literal also match its other case if it is a letter
+     */
+    auto make_case_insensitive() -> void override { m_case_insensitive = true; }
+
+    /**
+     * @return The literal as a pattern, escaped if necessary
+     */
+    [[nodiscard]] auto serialize() const -> std::string override;
+
+    /**
+     * @return 1 as RegexASTLiteral is a leaf node
+     */
+    [[nodiscard]] auto get_expanded_size() const -> uint64_t override { return 1; }
+
+    /**
+     * @return 1 as RegexASTLiteral is a leaf node
+     */
+    [[nodiscard]] auto get_depth() const -> uint32_t override { return 1; }
+
     /**
      * Add the needed RegexNFA::states to the passed in nfa to handle a
      * RegexASTLiteral before transitioning to a pre-tagged end_state
@@ -90,6 +138,7 @@ public:
 
 private:
     uint32_t m_character;
+    bool m_case_insensitive{false};
 };
 
 template <typename NFAStateType>
@@ -127,6 +176,34 @@ public:
         // Do nothing
     }
 
+    /**
+     * Does nothing as RegexASTInteger only contains digits
+     */
+    auto make_case_insensitive() -> void override {
+        // Do nothing
+    }
+
+    /**
+     * @return The integer's digits
+     */
+    [[nodiscard]] auto serialize() const -> std::string override {
+        std::string pattern;
+        for (uint32_t i : m_digits) {
+            pattern += static_cast<char>(i + '0');
+        }
+        return pattern;
+    }
+
+    /**
+     * @return 1 as RegexASTInteger is a leaf node
+     */
+    [[nodiscard]] auto get_expanded_size() const -> uint64_t override { return 1; }
+
+    /**
+     * @return 1 as RegexASTInteger is a leaf node
+     */
+    [[nodiscard]] auto get_depth() const -> uint32_t override { return 1; }
+
     /**
      * Add the needed RegexNFA::states to the passed in nfa to handle a
      * RegexASTInteger before transitioning to a pre-tagged end_state
@@ -209,24 +286,38 @@ public:
         if (delimiters.empty()) {
             return;
         }
-        m_ranges.clear();
         std::sort(delimiters.begin(), delimiters.end());
-        if (delimiters[0] != 0) {
-            Range range(0, delimiters[0] - 1);
-            m_ranges.push_back(range);
-        }
-        for (uint32_t i = 1; i < delimiters.size(); i++) {
-            if (delimiters[i] - delimiters[i - 1] > 1) {
-                Range range(delimiters[i - 1] + 1, delimiters[i] - 1);
-                m_ranges.push_back(range);
-            }
-        }
-        if (delimiters.back() != cUnicodeMax) {
-            Range range(delimiters.back() + 1, cUnicodeMax);
-            m_ranges.push_back(range);
+        std::vector<Range> delimiter_ranges;
+        for (uint32_t const delimiter : delimiters) {
+            delimiter_ranges.emplace_back(delimiter, delimiter);
         }
+        m_ranges = complement(merge(delimiter_ranges));
     }
 
+    /**
+     * Adds the other case of every letter in the group's ranges to the group.
+     * For a negated group, this means neither case is matched.
+     */
+    auto make_case_insensitive() -> void override;
+
+    /**
+     * Serializes the group as `.` if it is a wildcard, or as a bracket
+     * expression otherwise. Characters above 0xFF are left out since the lexer
+     * can't match them.
+     * @return The group as a pattern
+     */
+    [[nodiscard]] auto serialize() const -> std::string override;
+
+    /**
+     * @return 1 as RegexASTGroup is a leaf node
+     */
+    [[nodiscard]] auto get_expanded_size() const -> uint64_t override { return 1; }
+
+    /**
+     * @return 1 as RegexASTGroup is a leaf node
+     */
+    [[nodiscard]] auto get_depth() const -> uint32_t override { return 1; }
+
     /**
      * Add the needed RegexNFA::states to the passed in nfa to handle a
      * RegexASTGroup before transitioning to a pre-tagged end_state
@@ -241,22 +332,36 @@ public:
 
     auto set_is_wildcard_true() -> void { m_is_wildcard = true; }
 
-private:
+    [[nodiscard]] auto is_negated() const -> bool { return m_negate; }
+
     /**
-     * Merges multiple ranges such that the resulting m_ranges is sorted and
-     * non-overlapping @param ranges
+     * @return The ranges as added to the group (unsorted, possibly
+     * overlapping, and not yet complemented if the group is negated)
+     */
+    [[nodiscard]] auto get_ranges() const -> std::vector<Range> const& { return m_ranges; }
+
+    /**
+     * Merges multiple ranges such that the result is sorted and
+     * non-overlapping. Can be used to build groups programmatically (e.g., a
+     * group of all non-delimiters).
+     * @param ranges Ranges sorted by their lower bound
      * @return std::vector<Range>
      */
     static auto merge(std::vector<Range> const& ranges) -> std::vector<Range>;
 
     /**
-     * Takes the compliment (in the cast of regex `^` at the start of a group)
-     * of multiple ranges such that m_ranges is sorted and non-overlapping
-     * @param ranges
+     * Takes the complement (in the case of regex `^` at the start of a group)
+     * of multiple ranges over [0, cUnicodeMax] such that the result is sorted
+     * and non-overlapping. The UTF-16 surrogates ([cUnicodeSurrogateMin,
+     * cUnicodeSurrogateMax]) are never part of the result since they can't
+     * appear in valid UTF-8 input.
+     * @param ranges Ranges that are sorted and non-overlapping (e.g., the
+     * output of merge)
      * @return std::vector<Range>
      */
     static auto complement(std::vector<Range> const& ranges) -> std::vector<Range>;
 
+private:
     bool m_is_wildcard{false};
     bool m_negate{true};
     std::vector<Range> m_ranges;
@@ -300,6 +405,33 @@ public:
         m_right->remove_delimiters_from_wildcard(delimiters);
     }
 
+    /**
+     * Makes every descendant of this RegexASTOr node case insensitive
+     */
+    auto make_case_insensitive() -> void override {
+        m_left->make_case_insensitive();
+        m_right->make_case_insensitive();
+    }
+
+    /**
+     * @return Both alternatives as parenthesized patterns separated by `|`
+     */
+    [[nodiscard]] auto serialize() const -> std::string override {
+        return "(" + m_left->serialize() + ")|(" + m_right->serialize() + ")";
+    }
+
+    /**
+     * @return The sum of the expanded sizes of both children
+     */
+    [[nodiscard]] auto get_expanded_size() const -> uint64_t override;
+
+    /**
+     * @return 1 plus the depth of the deeper child
+     */
+    [[nodiscard]] auto get_depth() const -> uint32_t override {
+        return 1 + std::max(m_left->get_depth(), m_right->get_depth());
+    }
+
     /**
      * Add the needed RegexNFA::states to the passed in nfa to handle a
      * RegexASTOr before transitioning to a pre-tagged end_state
@@ -351,6 +483,33 @@ public:
         m_right->remove_delimiters_from_wildcard(delimiters);
     }
 
+    /**
+     * Makes every descendant of this RegexASTCat node case insensitive
+     */
+    auto make_case_insensitive() -> void override {
+        m_left->make_case_insensitive();
+        m_right->make_case_insensitive();
+    }
+
+    /**
+     * @return The left pattern followed by the right pattern
+     */
+    [[nodiscard]] auto serialize() const -> std::string override {
+        return m_left->serialize() + m_right->serialize();
+    }
+
+    /**
+     * @return The sum of the expanded sizes of both children
+     */
+    [[nodiscard]] auto get_expanded_size() const -> uint64_t override;
+
+    /**
+     * @return 1 plus the depth of the deeper child
+     */
+    [[nodiscard]] auto get_depth() const -> uint32_t override {
+        return 1 + std::max(m_left->get_depth(), m_right->get_depth());
+    }
+
     /**
      * Add the needed RegexNFA::states to the passed in nfa to handle a
      * RegexASTCat before transitioning to a pre-tagged end_state
@@ -405,6 +564,28 @@ public:
         m_operand->remove_delimiters_from_wildcard(delimiters);
     }
 
+    /**
+     * Makes the operand of this RegexASTMultiplication node case insensitive
+     */
+    auto make_case_insensitive() -> void override { m_operand->make_case_insensitive(); }
+
+    /**
+     * @return The operand (parenthesized unless it is a literal or group)
+     * followed by its repetition (e.g., `*`, `+`, `{N}`, `{N,M}`, or `{N,}`)
+     */
+    [[nodiscard]] auto serialize() const -> std::string override;
+
+    /**
+     * @return The operand's expanded size multiplied by the number of times
+     * it's repeated
+     */
+    [[nodiscard]] auto get_expanded_size() const -> uint64_t override;
+
+    /**
+     * @return 1 plus the depth of the operand
+     */
+    [[nodiscard]] auto get_depth() const -> uint32_t override { return 1 + m_operand->get_depth(); }
+
     /**
      * Add the needed RegexNFA::states to the passed in nfa to handle a
      * RegexASTMultiplication before transitioning to a pre-tagged end_state
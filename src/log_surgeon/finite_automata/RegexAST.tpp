@@ -4,20 +4,131 @@
 #include <algorithm>
 #include <cassert>
 #include <cstdint>
+#include <cstdio>
+#include <limits>
 #include <stdexcept>
+#include <string>
+#include <string_view>
 
 #include <log_surgeon/Constants.hpp>
 #include <log_surgeon/finite_automata/RegexNFA.hpp>
 #include <log_surgeon/finite_automata/UnicodeIntervalTree.hpp>
 
 namespace log_surgeon::finite_automata {
+namespace {
+    constexpr uint32_t cCaseDifference = 'a' - 'A';
+
+    /**
+     * @param character
+     * @return The other case of character if it is a letter, or character
+     * otherwise
+     */
+    // TODO: Handle UTF-8 (multi-byte transitions) as well
+    [[maybe_unused]] auto get_other_case(uint32_t character) -> uint32_t {
+        if ('a' <= character && character <= 'z') {
+            return character - cCaseDifference;
+        }
+        if ('A' <= character && character <= 'Z') {
+            return character + cCaseDifference;
+        }
+        return character;
+    }
+
+    /**
+     * @param character
+     * @return character as it would be written in a schema pattern (e.g., escaped
+     * if it is one of SchemaParser's special regex characters)
+     */
+    // TODO: Handle UTF-8 (multi-byte transitions) as well
+    [[maybe_unused]] auto serialize_character(uint32_t character) -> std::string {
+        // Must match SchemaParser::m_special_regex_characters
+        constexpr std::string_view cSpecialRegexCharacters{"()*+-.[]\\^{}|"};
+        switch (character) {
+            case '\t':
+                return "\\t";
+            case '\n':
+                return "\\n";
+            case '\v':
+                return "\\v";
+            case '\f':
+                return "\\f";
+            case '\r':
+                return "\\r";
+            default:
+                break;
+        }
+        if (std::string_view::npos != cSpecialRegexCharacters.find(static_cast<char>(character))
+            && character < cSizeOfByte)
+        {
+            return std::string{'\\', static_cast<char>(character)};
+        }
+        if (' ' <= character && character <= '~') {
+            return std::string(1, static_cast<char>(character));
+        }
+        if (character >= cSizeOfByte) {
+            throw std::runtime_error(
+                    "RegexAST: character " + std::to_string(character)
+                    + " can't be serialized, only characters up to 0xFF are supported."
+            );
+        }
+        char hex[5];
+        std::snprintf(hex, sizeof(hex), "\\x%02X", character);
+        return hex;
+    }
+
+    /**
+     * @param lhs
+     * @param rhs
+     * @return lhs + rhs, or UINT64_MAX if the sum would overflow
+     */
+    [[maybe_unused]] auto saturating_add(uint64_t lhs, uint64_t rhs) -> uint64_t {
+        if (lhs > std::numeric_limits<uint64_t>::max() - rhs) {
+            return std::numeric_limits<uint64_t>::max();
+        }
+        return lhs + rhs;
+    }
+
+    /**
+     * @param lhs
+     * @param rhs
+     * @return lhs * rhs, or UINT64_MAX if the product would overflow
+     */
+    [[maybe_unused]] auto saturating_multiply(uint64_t lhs, uint64_t rhs) -> uint64_t {
+        if (0 != lhs && rhs > std::numeric_limits<uint64_t>::max() / lhs) {
+            return std::numeric_limits<uint64_t>::max();
+        }
+        return lhs * rhs;
+    }
+}  // namespace
 
 template <typename NFAStateType>
 RegexASTLiteral<NFAStateType>::RegexASTLiteral(uint32_t character) : m_character(character) {}
 
+template <typename NFAStateType>
+auto RegexASTLiteral<NFAStateType>::set_possible_inputs_to_true(bool is_possible_input[]) const
+        -> void {
+    is_possible_input[m_character] = true;
+    if (m_case_insensitive) {
+        is_possible_input[get_other_case(m_character)] = true;
+    }
+}
+
 template <typename NFAStateType>
 void RegexASTLiteral<NFAStateType>::add(RegexNFA<NFAStateType>* nfa, NFAStateType* end_state) {
     nfa->add_root_interval(Interval(m_character, m_character), end_state);
+    if (m_case_insensitive && get_other_case(m_character) != m_character) {
+        uint32_t const other_case = get_other_case(m_character);
+        nfa->add_root_interval(Interval(other_case, other_case), end_state);
+    }
+}
+
+template <typename NFAStateType>
+auto RegexASTLiteral<NFAStateType>::serialize() const -> std::string {
+    if (m_case_insensitive && get_other_case(m_character) != m_character) {
+        return "[" + serialize_character(m_character)
+               + serialize_character(get_other_case(m_character)) + "]";
+    }
+    return serialize_character(m_character);
 }
 
 template <typename NFAStateType>
@@ -53,6 +164,11 @@ void RegexASTOr<NFAStateType>::add(RegexNFA<NFAStateType>* nfa, NFAStateType* en
     m_right->add(nfa, end_state);
 }
 
+template <typename NFAStateType>
+auto RegexASTOr<NFAStateType>::get_expanded_size() const -> uint64_t {
+    return saturating_add(m_left->get_expanded_size(), m_right->get_expanded_size());
+}
+
 template <typename NFAStateType>
 RegexASTCat<NFAStateType>::RegexASTCat(
         std::unique_ptr<RegexAST<NFAStateType>> left,
@@ -71,6 +187,11 @@ void RegexASTCat<NFAStateType>::add(RegexNFA<NFAStateType>* nfa, NFAStateType* e
     nfa->set_root(saved_root);
 }
 
+template <typename NFAStateType>
+auto RegexASTCat<NFAStateType>::get_expanded_size() const -> uint64_t {
+    return saturating_add(m_left->get_expanded_size(), m_right->get_expanded_size());
+}
+
 template <typename NFAStateType>
 RegexASTMultiplication<NFAStateType>::RegexASTMultiplication(
         std::unique_ptr<RegexAST<NFAStateType>> operand,
@@ -117,6 +238,36 @@ void RegexASTMultiplication<NFAStateType>::add(
     nfa->set_root(saved_root);
 }
 
+template <typename NFAStateType>
+auto RegexASTMultiplication<NFAStateType>::serialize() const -> std::string {
+    std::string pattern = m_operand->serialize();
+    if (nullptr == dynamic_cast<RegexASTLiteral<NFAStateType> const*>(m_operand.get())
+        && nullptr == dynamic_cast<RegexASTGroup<NFAStateType> const*>(m_operand.get()))
+    {
+        pattern = "(" + pattern + ")";
+    }
+    if (is_infinite()) {
+        if (0 == m_min) {
+            return pattern + "*";
+        }
+        if (1 == m_min) {
+            return pattern + "+";
+        }
+        return pattern + "{" + std::to_string(m_min) + ",}";
+    }
+    if (m_min == m_max) {
+        return pattern + "{" + std::to_string(m_min) + "}";
+    }
+    return pattern + "{" + std::to_string(m_min) + "," + std::to_string(m_max) + "}";
+}
+
+template <typename NFAStateType>
+auto RegexASTMultiplication<NFAStateType>::get_expanded_size() const -> uint64_t {
+    // add() adds the operand once more than m_min if there's no upper bound
+    uint64_t const num_repetitions = is_infinite() ? uint64_t{m_min} + 1 : m_max;
+    return saturating_multiply(m_operand->get_expanded_size(), num_repetitions);
+}
+
 template <typename NFAStateType>
 RegexASTGroup<NFAStateType>::RegexASTGroup() = default;
 
@@ -142,8 +293,8 @@ RegexASTGroup<NFAStateType>::RegexASTGroup(
 )
         : m_negate(left->m_negate),
           m_ranges(left->m_ranges) {
-    assert(right->m_ranges.size() == 1);  // Only add LiteralRange
-    m_ranges.push_back(right->m_ranges[0]);
+    assert(false == right->m_negate);  // Only add a LiteralRange or a class
+    m_ranges.insert(m_ranges.end(), right->m_ranges.begin(), right->m_ranges.end());
 }
 
 template <typename NFAStateType>
@@ -158,9 +309,10 @@ RegexASTGroup<NFAStateType>::RegexASTGroup(RegexASTLiteral<NFAStateType>* right)
 }
 
 template <typename NFAStateType>
-RegexASTGroup<NFAStateType>::RegexASTGroup(RegexASTGroup<NFAStateType>* right) : m_negate(false) {
-    assert(right->m_ranges.size() == 1);  // Only add LiteralRange
-    m_ranges.push_back(right->m_ranges[0]);
+RegexASTGroup<NFAStateType>::RegexASTGroup(RegexASTGroup<NFAStateType>* right)
+        : m_negate(false),
+          m_ranges(right->m_ranges) {
+    assert(false == right->m_negate);  // Only add a LiteralRange or a class
 }
 
 template <typename NFAStateType>
@@ -175,8 +327,16 @@ RegexASTGroup<NFAStateType>::RegexASTGroup(
                 "to README-Schema.md for more details."
         );
     }
+    if (right->get_character() < left->get_character()) {
+        throw std::runtime_error(
+                "RegexASTGroup3: right < left: A bracket expression in the schema contains the "
+                "invalid range '"
+                + std::string(1, static_cast<char>(left->get_character())) + "-"
+                + std::string(1, static_cast<char>(right->get_character()))
+                + "', ranges must be written from the lower to the higher character."
+        );
+    }
     m_negate = false;
-    assert(right->get_character() > left->get_character());
     m_ranges.emplace_back(left->get_character(), right->get_character());
 }
 
@@ -219,20 +379,71 @@ auto RegexASTGroup<NFAStateType>::merge(std::vector<Range> const& ranges)
 template <typename NFAStateType>
 auto RegexASTGroup<NFAStateType>::complement(std::vector<Range> const& ranges)
         -> std::vector<typename RegexASTGroup<NFAStateType>::Range> {
+    // Exclude the surrogates by treating them as part of the ranges
+    std::vector<Range> excluded = ranges;
+    excluded.emplace_back(cUnicodeSurrogateMin, cUnicodeSurrogateMax);
+    std::sort(excluded.begin(), excluded.end());
+    excluded = RegexASTGroup::merge(excluded);
+
     std::vector<Range> complemented;
     uint32_t low = 0;
-    for (Range const& r : ranges) {
-        if (r.first > 0) {
+    for (Range const& r : excluded) {
+        if (r.first > low) {
             complemented.emplace_back(low, r.first - 1);
         }
         low = r.second + 1;
     }
-    if (low > 0) {
+    if (low <= cUnicodeMax) {
         complemented.emplace_back(low, cUnicodeMax);
     }
     return complemented;
 }
 
+template <typename NFAStateType>
+auto RegexASTGroup<NFAStateType>::make_case_insensitive() -> void {
+    // TODO: Handle UTF-8 (multi-byte transitions) as well
+    std::vector<Range> other_case_ranges;
+    for (Range const& range : m_ranges) {
+        uint32_t const lower_min = std::max(range.first, static_cast<uint32_t>('a'));
+        uint32_t const lower_max = std::min(range.second, static_cast<uint32_t>('z'));
+        if (lower_min <= lower_max) {
+            other_case_ranges.emplace_back(
+                    lower_min - cCaseDifference,
+                    lower_max - cCaseDifference
+            );
+        }
+        uint32_t const upper_min = std::max(range.first, static_cast<uint32_t>('A'));
+        uint32_t const upper_max = std::min(range.second, static_cast<uint32_t>('Z'));
+        if (upper_min <= upper_max) {
+            other_case_ranges.emplace_back(
+                    upper_min + cCaseDifference,
+                    upper_max + cCaseDifference
+            );
+        }
+    }
+    m_ranges.insert(m_ranges.end(), other_case_ranges.begin(), other_case_ranges.end());
+}
+
+template <typename NFAStateType>
+auto RegexASTGroup<NFAStateType>::serialize() const -> std::string {
+    if (m_is_wildcard) {
+        return ".";
+    }
+    std::string pattern = m_negate ? "[^" : "[";
+    for (auto const& [begin, end] : m_ranges) {
+        // TODO: Handle UTF-8 (multi-byte transitions) as well
+        if (begin >= cSizeOfByte) {
+            continue;
+        }
+        uint32_t const byte_end = std::min(end, cSizeOfByte - 1);
+        pattern += serialize_character(begin);
+        if (byte_end > begin) {
+            pattern += "-" + serialize_character(byte_end);
+        }
+    }
+    return pattern + "]";
+}
+
 template <typename NFAStateType>
 void RegexASTGroup<NFAStateType>::add(RegexNFA<NFAStateType>* nfa, NFAStateType* end_state) {
     std::sort(this->m_ranges.begin(), this->m_ranges.end());
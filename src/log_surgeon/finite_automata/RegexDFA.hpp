@@ -4,7 +4,10 @@
 #include <algorithm>
 #include <cstdint>
 #include <memory>
+#include <optional>
 #include <set>
+#include <string>
+#include <string_view>
 #include <utility>
 #include <vector>
 
@@ -117,6 +120,38 @@ public:
 
     auto get_root() const -> DFAStateType const* { return m_states.at(0).get(); }
 
+    /**
+     * @return The number of states in the DFA
+     */
+    [[nodiscard]] auto get_num_states() const -> size_t { return m_states.size(); }
+
+    /**
+     * Serializes the DFA into the graphviz DOT format for inspecting the
+     * automaton a lexer actually uses. States are numbered in creation order
+     * (the root is 0), accepting states list their tags (schema type IDs), and
+     * consecutive bytes leading to the same state are merged into one edge.
+     * @return The DOT representation of the DFA
+     */
+    [[nodiscard]] auto serialize_to_dot() const -> std::string;
+
+    /**
+     * @param input
+     * @return Whether the DFA accepts the whole of input
+     */
+    [[nodiscard]] auto matches(std::string_view input) const -> bool;
+
+    /**
+     * Finds the longest prefix of input that the DFA accepts (i.e., the lexeme
+     * a lexer using the DFA would match at the start of input)
+     * @param input
+     * @return The length of the longest accepted prefix and the accepting
+     * state it ends in (whose tags are the rules that match it), or
+     * std::nullopt if the DFA doesn't accept any prefix of input (including
+     * the empty one)
+     */
+    [[nodiscard]] auto get_longest_match(std::string_view input) const
+            -> std::optional<std::pair<size_t, DFAStateType const*>>;
+
     /**
      * Compares this dfa with dfa_in to determine the set of schema types in
      * this dfa that are reachable by any type in dfa_in. A type is considered
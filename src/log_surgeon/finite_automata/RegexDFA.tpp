@@ -1,7 +1,30 @@
 #ifndef LOG_SURGEON_FINITE_AUTOMATA_REGEX_DFA_TPP
 #define LOG_SURGEON_FINITE_AUTOMATA_REGEX_DFA_TPP
 
+#include <algorithm>
+#include <cstdio>
+#include <map>
+#include <string>
+
 namespace log_surgeon::finite_automata {
+namespace {
+    /**
+     * @param byte
+     * @return A representation of the byte that is safe to use inside a
+     * quoted DOT label
+     */
+    [[maybe_unused]] auto byte_to_dot_label(uint32_t byte) -> std::string {
+        if ('"' == byte || '\\' == byte) {
+            return std::string("\\") + static_cast<char>(byte);
+        }
+        if (byte > ' ' && byte < 0x7F) {
+            return {static_cast<char>(byte)};
+        }
+        char hex[6];
+        std::snprintf(hex, sizeof(hex), "\\\\x%02X", byte);
+        return {hex};
+    }
+}  // namespace
 
 template <RegexDFAStateType stateType>
 auto RegexDFAState<stateType>::next(uint32_t character) const -> RegexDFAState<stateType>* {
@@ -45,14 +68,112 @@ auto RegexDFA<DFAStateType>::new_state(std::set<NFAStateType*> const& set) -> DF
     std::unique_ptr<DFAStateType> ptr = std::make_unique<DFAStateType>();
     m_states.push_back(std::move(ptr));
     DFAStateType* state = m_states.back().get();
+    // Order the tags by when their rules were added to the NFA, rather than by
+    // the addresses of the NFA states in the set, so that they follow the
+    // schema's rule order and don't change between runs
+    std::vector<NFAStateType const*> accepting_states;
     for (NFAStateType const* s : set) {
         if (s->is_accepting()) {
+            accepting_states.push_back(s);
+        }
+    }
+    std::sort(
+            accepting_states.begin(),
+            accepting_states.end(),
+            [](NFAStateType const* lhs, NFAStateType const* rhs) {
+                return lhs->get_id() < rhs->get_id();
+            }
+    );
+    for (NFAStateType const* s : accepting_states) {
+        auto const& tags = state->get_tags();
+        if (std::find(tags.begin(), tags.end(), s->get_tag()) == tags.end()) {
             state->add_tag(s->get_tag());
         }
     }
     return state;
 }
 
+template <typename DFAStateType>
+auto RegexDFA<DFAStateType>::serialize_to_dot() const -> std::string {
+    std::map<DFAStateType const*, size_t> state_ids;
+    for (size_t i = 0; i < m_states.size(); i++) {
+        state_ids[m_states[i].get()] = i;
+    }
+    std::string dot = "digraph RegexDFA {\n    rankdir=LR;\n";
+    for (size_t i = 0; i < m_states.size(); i++) {
+        DFAStateType const* state = m_states[i].get();
+        if (state->is_accepting()) {
+            std::string tags;
+            for (int const tag : state->get_tags()) {
+                if (false == tags.empty()) {
+                    tags += ",";
+                }
+                tags += std::to_string(tag);
+            }
+            dot += "    " + std::to_string(i) + " [shape=doublecircle, label=\"" + std::to_string(i)
+                   + "\\ntags: " + tags + "\"];\n";
+        } else {
+            dot += "    " + std::to_string(i) + " [shape=circle];\n";
+        }
+    }
+    // TODO: Handle UTF-8 (multi-byte transitions) as well
+    for (size_t i = 0; i < m_states.size(); i++) {
+        DFAStateType const* state = m_states[i].get();
+        uint32_t byte = 0;
+        while (byte < cSizeOfByte) {
+            DFAStateType const* dest_state = state->next(byte);
+            uint32_t last_byte = byte;
+            while (last_byte + 1 < cSizeOfByte && state->next(last_byte + 1) == dest_state) {
+                last_byte++;
+            }
+            if (nullptr != dest_state) {
+                std::string label = byte_to_dot_label(byte);
+                if (last_byte != byte) {
+                    label += "-" + byte_to_dot_label(last_byte);
+                }
+                dot += "    " + std::to_string(i) + " -> "
+                       + std::to_string(state_ids.at(dest_state)) + " [label=\"" + label
+                       + "\"];\n";
+            }
+            byte = last_byte + 1;
+        }
+    }
+    dot += "}\n";
+    return dot;
+}
+
+template <typename DFAStateType>
+auto RegexDFA<DFAStateType>::matches(std::string_view input) const -> bool {
+    DFAStateType const* state = get_root();
+    for (char const character : input) {
+        state = state->next(static_cast<unsigned char>(character));
+        if (nullptr == state) {
+            return false;
+        }
+    }
+    return state->is_accepting();
+}
+
+template <typename DFAStateType>
+auto RegexDFA<DFAStateType>::get_longest_match(std::string_view input) const
+        -> std::optional<std::pair<size_t, DFAStateType const*>> {
+    std::optional<std::pair<size_t, DFAStateType const*>> longest_match;
+    DFAStateType const* state = get_root();
+    if (state->is_accepting()) {
+        longest_match.emplace(0, state);
+    }
+    for (size_t i = 0; i < input.size(); i++) {
+        state = state->next(static_cast<unsigned char>(input[i]));
+        if (nullptr == state) {
+            break;
+        }
+        if (state->is_accepting()) {
+            longest_match.emplace(i + 1, state);
+        }
+    }
+    return longest_match;
+}
+
 template <typename DFAStateType>
 auto RegexDFA<DFAStateType>::get_intersect(std::unique_ptr<RegexDFA> const& dfa_in) const
         -> std::set<uint32_t> {
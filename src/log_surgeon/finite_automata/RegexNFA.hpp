@@ -23,6 +23,14 @@ class RegexNFAState {
 public:
     using Tree = UnicodeIntervalTree<RegexNFAState<stateType>*>;
 
+    auto set_id(uint32_t id) -> void { m_id = id; }
+
+    /**
+     * @return The order in which the state was created in its NFA, which (unlike
+     * the state's address) is the same every time a schema is compiled
+     */
+    [[nodiscard]] auto get_id() const -> uint32_t const& { return m_id; }
+
     auto set_accepting(bool accepting) -> void { m_accepting = accepting; }
 
     [[nodiscard]] auto is_accepting() const -> bool const& { return m_accepting; }
@@ -77,6 +85,7 @@ public:
     auto add_interval(Interval interval, RegexNFAState<stateType>* dest_state) -> void;
 
 private:
+    uint32_t m_id{0};
     bool m_accepting;
     int m_tag;
     std::vector<RegexNFAState<stateType>*> m_epsilon_transitions;
@@ -118,6 +127,7 @@ public:
 
 private:
     std::vector<std::unique_ptr<NFAStateType>> m_states;
+    uint32_t m_next_state_id{0};
     NFAStateType* m_root;
 };
 }  // namespace log_surgeon::finite_automata
@@ -180,6 +180,7 @@ template <typename NFAStateType>
 auto RegexNFA<NFAStateType>::new_state() -> NFAStateType* {
     std::unique_ptr<NFAStateType> ptr = std::make_unique<NFAStateType>();
     NFAStateType* state = ptr.get();
+    state->set_id(m_next_state_id++);
     m_states.push_back(std::move(ptr));
     return state;
 }